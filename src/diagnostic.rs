@@ -0,0 +1,84 @@
+use crate::error::{ParseError, ParseErrorKind};
+
+/// Renders a `ParseError` against the original source as a single
+/// caret-annotated diagnostic, pointing at the offending span.
+pub fn render(source: &str, error: &ParseError) -> String {
+    let span = error.span();
+    let line_start = line_byte_offset(source, span.line);
+    let line_text = source[line_start..]
+        .split('\n')
+        .next()
+        .unwrap_or_default();
+
+    let col_start = span.start.saturating_sub(line_start);
+    let col_end = span.end.saturating_sub(line_start).max(col_start + 1);
+
+    let mut out = String::new();
+    out.push_str(&format!("error: {}\n", describe(error.kind())));
+    out.push_str(&format!(" --> line {}\n", span.line));
+    out.push_str(line_text);
+    out.push('\n');
+    out.push_str(&" ".repeat(col_start));
+    out.push_str(&"^".repeat(col_end - col_start));
+    out
+}
+
+fn line_byte_offset(source: &str, line: usize) -> usize {
+    source
+        .split('\n')
+        .take(line - 1)
+        .map(|l| l.len() + 1)
+        .sum()
+}
+
+fn describe(kind: &ParseErrorKind) -> &'static str {
+    match kind {
+        ParseErrorKind::InvalidToken => "unexpected token",
+        ParseErrorKind::InvalidString => "invalid string literal",
+        ParseErrorKind::InvalidNumber => "invalid number literal",
+        ParseErrorKind::InvalidValue => "invalid literal value",
+        ParseErrorKind::UnterminatedString => "unterminated string",
+        ParseErrorKind::EndOfStream => "unexpected end of input",
+        ParseErrorKind::NotAPrimitive => "expected a primitive value",
+        ParseErrorKind::TrailingTokens => "unexpected trailing input",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::{error::ParseErrorKind, parser::Parser, token::TokenStream};
+
+    #[test]
+    fn test_render_points_at_span() {
+        let source = "{\"name\": }";
+        let mut parser = Parser::new(TokenStream::new(source));
+        let error = parser.parse().unwrap_err();
+
+        let rendered = render(source, &error);
+
+        assert!(rendered.contains("error: expected a primitive value"));
+        assert!(rendered.contains(" --> line 1"));
+        assert!(rendered.contains(source));
+        assert!(rendered.contains('^'));
+    }
+
+    #[test]
+    fn test_render_second_line() {
+        let source = "[1,\n2,]";
+        let mut parser = Parser::new(TokenStream::new(source));
+        let error = parser.parse().unwrap_err();
+
+        assert_eq!(error.kind(), &ParseErrorKind::InvalidToken);
+        assert!(render(source, &error).contains(" --> line 2"));
+    }
+
+    #[test]
+    fn test_render_tab_indented_line_does_not_panic() {
+        let source = "{\n\t\"a\": @}";
+        let mut parser = Parser::new(TokenStream::new(source));
+        let error = parser.parse().unwrap_err();
+
+        assert!(render(source, &error).contains(" --> line 2"));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::ast::{Container, JsonValue, Primitive};
+
+/// Serializes a `JsonValue` to its compact JSON representation.
+pub fn to_string(value: &JsonValue) -> String {
+    let mut buf = String::new();
+    encode_value(value, &mut buf);
+    buf
+}
+
+/// Serializes a `JsonValue` to a pretty-printed JSON representation,
+/// indenting nested members and elements by `indent` spaces per depth.
+pub fn to_string_pretty(value: &JsonValue, indent: usize) -> String {
+    let mut buf = String::new();
+    encode_value_pretty(value, indent, 0, &mut buf);
+    buf
+}
+
+fn encode_value(value: &JsonValue, buf: &mut String) {
+    match value {
+        JsonValue::Primitive(primitive) => encode_primitive(primitive, buf),
+        JsonValue::Container(Container::Array(elements)) => {
+            buf.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                encode_value(element, buf);
+            }
+            buf.push(']');
+        }
+        JsonValue::Container(Container::Object(members)) => {
+            buf.push('{');
+            for (i, member) in members.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                encode_string(member.name(), buf);
+                buf.push(':');
+                encode_value(member.value(), buf);
+            }
+            buf.push('}');
+        }
+    }
+}
+
+fn encode_value_pretty(value: &JsonValue, indent: usize, depth: usize, buf: &mut String) {
+    match value {
+        JsonValue::Primitive(primitive) => encode_primitive(primitive, buf),
+        JsonValue::Container(Container::Array(elements)) => {
+            if elements.is_empty() {
+                buf.push_str("[]");
+                return;
+            }
+
+            buf.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                buf.push('\n');
+                push_indent(buf, indent, depth + 1);
+                encode_value_pretty(element, indent, depth + 1, buf);
+            }
+            buf.push('\n');
+            push_indent(buf, indent, depth);
+            buf.push(']');
+        }
+        JsonValue::Container(Container::Object(members)) => {
+            if members.is_empty() {
+                buf.push_str("{}");
+                return;
+            }
+
+            buf.push('{');
+            for (i, member) in members.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                buf.push('\n');
+                push_indent(buf, indent, depth + 1);
+                encode_string(member.name(), buf);
+                buf.push_str(": ");
+                encode_value_pretty(member.value(), indent, depth + 1, buf);
+            }
+            buf.push('\n');
+            push_indent(buf, indent, depth);
+            buf.push('}');
+        }
+    }
+}
+
+fn encode_primitive(primitive: &Primitive, buf: &mut String) {
+    match primitive {
+        Primitive::Number(n) => buf.push_str(&format_number(*n)),
+        Primitive::String(s) => encode_string(s, buf),
+        Primitive::Boolean(b) => buf.push_str(if *b { "true" } else { "false" }),
+        Primitive::Null => buf.push_str("null"),
+    }
+}
+
+fn encode_string(s: &str, buf: &mut String) {
+    buf.push('"');
+    for char in s.chars() {
+        match char {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{c}' => buf.push_str("\\f"),
+            char if (char as u32) < 0x20 => {
+                buf.push_str(&format!("\\u{:04x}", char as u32));
+            }
+            char => buf.push(char),
+        }
+    }
+    buf.push('"');
+}
+
+fn push_indent(buf: &mut String, indent: usize, depth: usize) {
+    for _ in 0..indent * depth {
+        buf.push(' ');
+    }
+}
+
+/// Formats an `f64` the way JSON numbers are expected to read: integral
+/// values print without a trailing `.0`, everything else uses Rust's
+/// shortest round-tripping representation.
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        format!("{n}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_string, to_string_pretty};
+    use crate::ast::{Container, JsonValue, Member, Primitive};
+
+    #[test]
+    fn test_encode_primitives() {
+        assert_eq!(to_string(&JsonValue::Primitive(Primitive::Null)), "null");
+        assert_eq!(
+            to_string(&JsonValue::Primitive(Primitive::Boolean(true))),
+            "true"
+        );
+        assert_eq!(
+            to_string(&JsonValue::Primitive(Primitive::Number(30.0))),
+            "30"
+        );
+        assert_eq!(
+            to_string(&JsonValue::Primitive(Primitive::Number(3.5))),
+            "3.5"
+        );
+    }
+
+    #[test]
+    fn test_encode_string_escapes() {
+        let value = JsonValue::Primitive(Primitive::String("a\n\"b\"".to_string()));
+        assert_eq!(to_string(&value), "\"a\\n\\\"b\\\"\"");
+    }
+
+    #[test]
+    fn test_encode_array() {
+        let value = JsonValue::Container(Container::Array(vec![
+            JsonValue::Primitive(Primitive::Number(1.0)),
+            JsonValue::Primitive(Primitive::Number(2.0)),
+        ]));
+
+        assert_eq!(to_string(&value), "[1,2]");
+    }
+
+    #[test]
+    fn test_encode_object() {
+        let value = JsonValue::Container(Container::Object(vec![Member::new(
+            "name".to_string(),
+            JsonValue::Primitive(Primitive::String("Alice".to_string())),
+        )]));
+
+        assert_eq!(to_string(&value), "{\"name\":\"Alice\"}");
+    }
+
+    #[test]
+    fn test_encode_pretty_object() {
+        let value = JsonValue::Container(Container::Object(vec![Member::new(
+            "name".to_string(),
+            JsonValue::Primitive(Primitive::String("Alice".to_string())),
+        )]));
+
+        assert_eq!(to_string_pretty(&value, 2), "{\n  \"name\": \"Alice\"\n}");
+    }
+
+    #[test]
+    fn test_encode_empty_containers() {
+        assert_eq!(
+            to_string_pretty(&JsonValue::Container(Container::Array(Vec::new())), 2),
+            "[]"
+        );
+        assert_eq!(
+            to_string_pretty(&JsonValue::Container(Container::Object(Vec::new())), 2),
+            "{}"
+        );
+    }
+}
@@ -1,12 +1,14 @@
+use crate::span::Span;
+
 pub type Result<T> = std::result::Result<T, ParseError>;
 
 #[derive(Debug)]
 pub struct ParseError {
-    line: usize,
+    span: Span,
     kind: ParseErrorKind,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ParseErrorKind {
     InvalidToken,
     InvalidString,
@@ -15,10 +17,19 @@ pub enum ParseErrorKind {
     UnterminatedString,
     EndOfStream,
     NotAPrimitive,
+    TrailingTokens,
 }
 
 impl ParseError {
-    pub fn new(line: usize, kind: ParseErrorKind) -> Self {
-        Self { line, kind }
+    pub fn new(span: Span, kind: ParseErrorKind) -> Self {
+        Self { span, kind }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
     }
 }
@@ -0,0 +1,9 @@
+pub mod ast;
+pub mod diagnostic;
+pub mod encoder;
+pub mod error;
+pub mod parser;
+pub mod query;
+pub mod span;
+pub mod stream;
+pub mod token;
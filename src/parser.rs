@@ -11,13 +11,15 @@
 // <object> ::= '{' [ <member> *(', ' <member>) ] '}' ; A sequence of 'members'
 // <member> ::= <string> ': ' <json> ; A pair consisting of a name, and a JSON value
 
-use crate::ast::Primitive;
+use crate::ast::{Container, JsonValue, Member, Primitive};
 use crate::error::ParseError;
 use crate::error::ParseErrorKind;
 use crate::error::Result;
 use crate::token::{TokenKind, TokenStream};
 
-struct Parser<'a> {
+/// Recursive-descent parser that turns a `TokenStream` into a single
+/// `JsonValue`, the crate's public entry point for parsing a document.
+pub struct Parser<'a> {
     ts: TokenStream<'a>,
 }
 
@@ -26,13 +28,99 @@ impl<'a> Parser<'a> {
         Self { ts }
     }
 
-    pub fn parse(&mut self) -> Result<Vec<Primitive>> {
-        let mut result = Vec::new();
-        while !self.ts.end_of_stream() {
-            result.push(self.parse_primitive()?)
+    /// Parses a whole JSON document, erroring if tokens remain once the
+    /// first value has been consumed.
+    pub fn parse(&mut self) -> Result<JsonValue> {
+        let value = self.parse_value()?;
+
+        match self.ts.next() {
+            Ok(token) => Err(ParseError::new(token.span(), ParseErrorKind::TrailingTokens)),
+            Err(e) if *e.kind() == ParseErrorKind::EndOfStream => Ok(value),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue> {
+        let token = self.ts.peek()?;
+
+        match token.kind {
+            TokenKind::LeftSquareBracket => self.parse_array(),
+            TokenKind::LeftCurlyBracket => self.parse_object(),
+            _ => Ok(JsonValue::Primitive(self.parse_primitive()?)),
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue> {
+        self.ts.next()?;
+
+        let mut elements = Vec::new();
+
+        if self.ts.peek()?.kind == TokenKind::RightSquareBracket {
+            self.ts.next()?;
+            return Ok(JsonValue::Container(Container::Array(elements)));
+        }
+
+        loop {
+            elements.push(self.parse_value()?);
+
+            let token = self.ts.next()?;
+            match token.kind {
+                TokenKind::RightSquareBracket => break,
+                TokenKind::Comma => {
+                    if self.ts.peek()?.kind == TokenKind::RightSquareBracket {
+                        return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    }
+                }
+                _ => return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken)),
+            }
+        }
+
+        Ok(JsonValue::Container(Container::Array(elements)))
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue> {
+        self.ts.next()?;
+
+        let mut members = Vec::new();
+
+        if self.ts.peek()?.kind == TokenKind::RightCurlyBracket {
+            self.ts.next()?;
+            return Ok(JsonValue::Container(Container::Object(members)));
+        }
+
+        loop {
+            members.push(self.parse_member()?);
+
+            let token = self.ts.next()?;
+            match token.kind {
+                TokenKind::RightCurlyBracket => break,
+                TokenKind::Comma => {
+                    if self.ts.peek()?.kind == TokenKind::RightCurlyBracket {
+                        return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    }
+                }
+                _ => return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken)),
+            }
+        }
+
+        Ok(JsonValue::Container(Container::Object(members)))
+    }
+
+    fn parse_member(&mut self) -> Result<Member> {
+        let token = self.ts.next()?;
+        let name = match token.kind {
+            TokenKind::String(s) => s,
+            _ => return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken)),
+        };
+
+        let colon = self.ts.next()?;
+        if colon.kind != TokenKind::Colon {
+            return Err(ParseError::new(colon.span(), ParseErrorKind::InvalidToken));
         }
 
-        Ok(result)
+        let value = self.parse_value()?;
+
+        Ok(Member::new(name, value))
     }
 
     fn parse_primitive(&mut self) -> Result<Primitive> {
@@ -44,29 +132,331 @@ impl<'a> Parser<'a> {
             TokenKind::True => Ok(Primitive::Boolean(true)),
             TokenKind::False => Ok(Primitive::Boolean(false)),
             TokenKind::Null => Ok(Primitive::Null),
-            _ => Err(ParseError::new(token.line, ParseErrorKind::NotAPrimitive)),
+            _ => Err(ParseError::new(token.span(), ParseErrorKind::NotAPrimitive)),
         }
     }
+
+    /// Parses a whole document, collecting every error encountered
+    /// instead of stopping at the first one. On an unexpected token the
+    /// parser synchronizes to the next structural boundary (`,`, `}`,
+    /// `]`, or end of stream), records the error and keeps going. A value
+    /// that cannot be parsed is replaced by a placeholder `Null`; a member
+    /// whose key is not a string, and anything skipped after an element
+    /// or member, is dropped.
+    pub fn parse_recovering(&mut self) -> (Option<JsonValue>, Vec<ParseError>) {
+        let mut errors = Vec::new();
+        let value = self.parse_value_recovering(&mut errors);
+
+        match self.ts.next() {
+            Ok(token) => errors.push(ParseError::new(token.span(), ParseErrorKind::TrailingTokens)),
+            Err(e) if *e.kind() == ParseErrorKind::EndOfStream => {}
+            Err(e) => errors.push(e),
+        }
+
+        (value, errors)
+    }
+
+    fn parse_value_recovering(&mut self, errors: &mut Vec<ParseError>) -> Option<JsonValue> {
+        let token = match self.ts.peek() {
+            Ok(token) => token,
+            Err(e) => {
+                let at_end = *e.kind() == ParseErrorKind::EndOfStream;
+                errors.push(e);
+
+                if at_end {
+                    return None;
+                }
+
+                self.synchronize();
+                return Some(JsonValue::Primitive(Primitive::Null));
+            }
+        };
+
+        match token.kind {
+            TokenKind::LeftSquareBracket => Some(self.parse_array_recovering(errors)),
+            TokenKind::LeftCurlyBracket => Some(self.parse_object_recovering(errors)),
+            _ => match self.parse_primitive() {
+                Ok(primitive) => Some(JsonValue::Primitive(primitive)),
+                Err(e) => {
+                    errors.push(e);
+                    self.synchronize();
+                    Some(JsonValue::Primitive(Primitive::Null))
+                }
+            },
+        }
+    }
+
+    fn parse_array_recovering(&mut self, errors: &mut Vec<ParseError>) -> JsonValue {
+        self.ts.next().ok();
+
+        let mut elements = Vec::new();
+
+        if matches!(self.ts.peek(), Ok(token) if token.kind == TokenKind::RightSquareBracket) {
+            self.ts.next().ok();
+            return JsonValue::Container(Container::Array(elements));
+        }
+
+        loop {
+            let value = self.parse_value_recovering(errors);
+            let produced_value = value.is_some();
+            elements.extend(value);
+
+            if !produced_value && self.ts.end_of_stream() {
+                break;
+            }
+
+            match self.ts.next() {
+                Ok(token) if token.kind == TokenKind::RightSquareBracket => break,
+                Ok(token) if token.kind == TokenKind::Comma => continue,
+                Ok(token) => {
+                    errors.push(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    self.synchronize();
+
+                    match self.ts.next() {
+                        Ok(t) if t.kind == TokenKind::Comma => continue,
+                        _ => break,
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        JsonValue::Container(Container::Array(elements))
+    }
+
+    fn parse_object_recovering(&mut self, errors: &mut Vec<ParseError>) -> JsonValue {
+        self.ts.next().ok();
+
+        let mut members = Vec::new();
+
+        if matches!(self.ts.peek(), Ok(token) if token.kind == TokenKind::RightCurlyBracket) {
+            self.ts.next().ok();
+            return JsonValue::Container(Container::Object(members));
+        }
+
+        loop {
+            let member = self.parse_member_recovering(errors);
+            let produced_member = member.is_some();
+            members.extend(member);
+
+            if !produced_member && self.ts.end_of_stream() {
+                break;
+            }
+
+            match self.ts.next() {
+                Ok(token) if token.kind == TokenKind::RightCurlyBracket => break,
+                Ok(token) if token.kind == TokenKind::Comma => continue,
+                Ok(token) => {
+                    errors.push(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    self.synchronize();
+
+                    match self.ts.next() {
+                        Ok(t) if t.kind == TokenKind::Comma => continue,
+                        _ => break,
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        JsonValue::Container(Container::Object(members))
+    }
+
+    fn parse_member_recovering(&mut self, errors: &mut Vec<ParseError>) -> Option<Member> {
+        let token = match self.ts.next() {
+            Ok(token) => token,
+            Err(e) => {
+                errors.push(e);
+                self.synchronize();
+                return None;
+            }
+        };
+
+        let name = match token.kind {
+            TokenKind::String(s) => s,
+            _ => {
+                errors.push(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                self.synchronize();
+                return None;
+            }
+        };
+
+        match self.ts.next() {
+            Ok(colon) if colon.kind == TokenKind::Colon => {}
+            Ok(colon) => {
+                errors.push(ParseError::new(colon.span(), ParseErrorKind::InvalidToken));
+                self.synchronize();
+                return Some(Member::new(name, JsonValue::Primitive(Primitive::Null)));
+            }
+            Err(e) => {
+                errors.push(e);
+                return None;
+            }
+        }
+
+        let value = self
+            .parse_value_recovering(errors)
+            .unwrap_or(JsonValue::Primitive(Primitive::Null));
+
+        Some(Member::new(name, value))
+    }
+
+    /// Resynchronizes at the next structural boundary (`,`, `}`, `]`,
+    /// or end of stream) without consuming it, so the caller can resume
+    /// parsing from a known-good position.
+    fn synchronize(&mut self) {
+        self.ts.skip_to_boundary();
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{ast::Primitive, token::TokenStream};
+    use crate::{
+        ast::{Container, JsonValue, Primitive},
+        token::TokenStream,
+    };
 
     use super::Parser;
 
     #[test]
     fn test_parse_primitive() {
-        let mut parser = Parser::new(TokenStream::new("35 \"meudeus\" false null"));
+        let mut parser = Parser::new(TokenStream::new("35"));
+        let ast = parser.parse().unwrap();
+
+        assert_eq!(ast, JsonValue::Primitive(Primitive::Number(35.0)));
+    }
+
+    #[test]
+    fn test_parse_array() {
+        let mut parser = Parser::new(TokenStream::new("[1, 2, 3]"));
         let ast = parser.parse().unwrap();
-        let mut iter = ast.iter();
-
-        assert_eq!(iter.next().unwrap(), &Primitive::Number(35.0));
-        assert_eq!(
-            iter.next().unwrap(),
-            &Primitive::String("\"meudeus\"".to_string())
-        );
-        assert_eq!(iter.next().unwrap(), &Primitive::Boolean(false));
-        assert_eq!(iter.next().unwrap(), &Primitive::Null);
+
+        match ast {
+            JsonValue::Container(Container::Array(elements)) => {
+                assert_eq!(elements.len(), 3);
+                assert_eq!(elements[0], JsonValue::Primitive(Primitive::Number(1.0)));
+                assert_eq!(elements[1], JsonValue::Primitive(Primitive::Number(2.0)));
+                assert_eq!(elements[2], JsonValue::Primitive(Primitive::Number(3.0)));
+            }
+            other => panic!("expected an array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_empty_array() {
+        let mut parser = Parser::new(TokenStream::new("[]"));
+        let ast = parser.parse().unwrap();
+
+        assert_eq!(ast, JsonValue::Container(Container::Array(Vec::new())));
+    }
+
+    #[test]
+    fn test_parse_object() {
+        let mut parser = Parser::new(TokenStream::new("{\"name\": \"Alice\", \"age\": 30}"));
+        let ast = parser.parse().unwrap();
+
+        match ast {
+            JsonValue::Container(Container::Object(members)) => {
+                assert_eq!(members.len(), 2);
+                assert_eq!(members[0].name(), "name");
+                assert_eq!(
+                    members[0].value(),
+                    &JsonValue::Primitive(Primitive::String("Alice".to_string()))
+                );
+                assert_eq!(members[1].name(), "age");
+                assert_eq!(
+                    members[1].value(),
+                    &JsonValue::Primitive(Primitive::Number(30.0))
+                );
+            }
+            other => panic!("expected an object, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_nested_containers() {
+        let mut parser = Parser::new(TokenStream::new("{\"values\": [1, [2, 3]]}"));
+
+        assert!(parser.parse().is_ok());
+    }
+
+    #[test]
+    fn test_trailing_comma_in_array_errors() {
+        let mut parser = Parser::new(TokenStream::new("[1, 2,]"));
+
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_trailing_tokens_error() {
+        let mut parser = Parser::new(TokenStream::new("1 2"));
+
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_parse_recovering_collects_multiple_errors() {
+        let mut parser = Parser::new(TokenStream::new("[1, @, 3, %, 5]"));
+        let (ast, errors) = parser.parse_recovering();
+
+        assert_eq!(errors.len(), 2);
+
+        match ast {
+            Some(JsonValue::Container(Container::Array(elements))) => {
+                assert_eq!(elements.len(), 5);
+                assert_eq!(elements[0], JsonValue::Primitive(Primitive::Number(1.0)));
+                assert_eq!(elements[1], JsonValue::Primitive(Primitive::Null));
+                assert_eq!(elements[2], JsonValue::Primitive(Primitive::Number(3.0)));
+                assert_eq!(elements[3], JsonValue::Primitive(Primitive::Null));
+                assert_eq!(elements[4], JsonValue::Primitive(Primitive::Number(5.0)));
+            }
+            other => panic!("expected a recovered array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_recovering_valid_document_has_no_errors() {
+        let mut parser = Parser::new(TokenStream::new("{\"name\": \"Alice\"}"));
+        let (ast, errors) = parser.parse_recovering();
+
+        assert!(errors.is_empty());
+        assert!(ast.is_some());
+    }
+
+    #[test]
+    fn test_parse_recovering_bad_member_value() {
+        let mut parser = Parser::new(TokenStream::new("{\"a\": 1, \"b\": @, \"c\": 3}"));
+        let (ast, errors) = parser.parse_recovering();
+
+        assert_eq!(errors.len(), 1);
+
+        match ast {
+            Some(JsonValue::Container(Container::Object(members))) => {
+                assert_eq!(members.len(), 3);
+                assert_eq!(members[1].name(), "b");
+                assert_eq!(
+                    members[1].value(),
+                    &JsonValue::Primitive(Primitive::Null)
+                );
+            }
+            other => panic!("expected a recovered object, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_recovering_bad_member_key_resynchronizes() {
+        let mut parser = Parser::new(TokenStream::new("{\"a\":1, @: 2, \"c\":3}"));
+        let (ast, errors) = parser.parse_recovering();
+
+        assert_eq!(errors.len(), 1);
+
+        match ast {
+            Some(JsonValue::Container(Container::Object(members))) => {
+                assert_eq!(members.len(), 2);
+                assert_eq!(members[0].name(), "a");
+                assert_eq!(members[1].name(), "c");
+            }
+            other => panic!("expected a recovered object, got {other:?}"),
+        }
     }
 }
@@ -0,0 +1,337 @@
+use crate::ast::{Container, JsonValue};
+
+/// One step in a parsed JSONPath expression.
+#[derive(Debug, PartialEq)]
+pub enum Selector {
+    /// `.name` or `['name']`
+    Child(String),
+    /// `[n]`
+    Index(usize),
+    /// `[*]` or `.*`
+    Wildcard,
+    /// `..`
+    RecursiveDescent,
+}
+
+/// Why a JSONPath expression could not be parsed.
+#[derive(Debug, PartialEq)]
+pub enum PathError {
+    /// A `[` selector was not directly followed by its closing `]`.
+    UnterminatedBracket,
+    /// A `[...]` held something other than an index, a quoted name, or
+    /// `*`.
+    InvalidIndex,
+    /// A character appeared where a selector was expected.
+    UnexpectedCharacter,
+}
+
+/// Parses a JSONPath expression such as `$.store.book[0].title`,
+/// `$..author`, `$.store.book[*].author` or `$['store']['book']` into
+/// the sequence of selectors `select` applies in order.
+pub fn parse_path(path: &str) -> Result<Vec<Selector>, PathError> {
+    let bytes = path.as_bytes();
+    let mut pointer = 0;
+    let mut selectors = Vec::new();
+
+    if pointer < bytes.len() && bytes[pointer] == b'$' {
+        pointer += 1;
+    }
+
+    while pointer < bytes.len() {
+        match bytes[pointer] as char {
+            '.' => {
+                pointer += 1;
+
+                if pointer < bytes.len() && bytes[pointer] as char == '.' {
+                    pointer += 1;
+                    selectors.push(Selector::RecursiveDescent);
+
+                    if pointer < bytes.len() && bytes[pointer] as char == '*' {
+                        pointer += 1;
+                        selectors.push(Selector::Wildcard);
+                    } else if pointer < bytes.len() && is_name_char(bytes[pointer] as char) {
+                        let start = pointer;
+                        while pointer < bytes.len() && is_name_char(bytes[pointer] as char) {
+                            pointer += 1;
+                        }
+                        selectors.push(Selector::Child(path[start..pointer].to_string()));
+                    }
+
+                    continue;
+                }
+
+                if pointer < bytes.len() && bytes[pointer] as char == '*' {
+                    pointer += 1;
+                    selectors.push(Selector::Wildcard);
+                    continue;
+                }
+
+                let start = pointer;
+                while pointer < bytes.len() && is_name_char(bytes[pointer] as char) {
+                    pointer += 1;
+                }
+
+                if start == pointer {
+                    return Err(PathError::UnexpectedCharacter);
+                }
+
+                selectors.push(Selector::Child(path[start..pointer].to_string()));
+            }
+            '[' => {
+                pointer += 1;
+
+                if pointer < bytes.len() && bytes[pointer] as char == '*' {
+                    pointer += 1;
+                    selectors.push(Selector::Wildcard);
+                } else if pointer < bytes.len() && matches!(bytes[pointer] as char, '\'' | '"') {
+                    let quote = bytes[pointer] as char;
+                    pointer += 1;
+                    let start = pointer;
+                    while pointer < bytes.len() && bytes[pointer] as char != quote {
+                        pointer += 1;
+                    }
+
+                    if pointer >= bytes.len() {
+                        return Err(PathError::UnterminatedBracket);
+                    }
+
+                    selectors.push(Selector::Child(path[start..pointer].to_string()));
+                    pointer += 1;
+                } else {
+                    let start = pointer;
+                    while pointer < bytes.len() && (bytes[pointer] as char).is_ascii_digit() {
+                        pointer += 1;
+                    }
+
+                    if start == pointer {
+                        return Err(PathError::InvalidIndex);
+                    }
+
+                    let index = path[start..pointer]
+                        .parse()
+                        .map_err(|_| PathError::InvalidIndex)?;
+                    selectors.push(Selector::Index(index));
+                }
+
+                if pointer < bytes.len() && bytes[pointer] as char == ']' {
+                    pointer += 1;
+                } else {
+                    return Err(PathError::UnterminatedBracket);
+                }
+            }
+            _ => return Err(PathError::UnexpectedCharacter),
+        }
+    }
+
+    Ok(selectors)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Applies a sequence of selectors to a parsed `JsonValue`, returning
+/// every matching sub-value, in the order they are encountered.
+pub fn select<'a>(value: &'a JsonValue, selectors: &[Selector]) -> Vec<&'a JsonValue> {
+    let mut current = vec![value];
+
+    for selector in selectors {
+        current = match selector {
+            Selector::Child(name) => current.into_iter().flat_map(|v| child(v, name)).collect(),
+            Selector::Index(index) => current
+                .into_iter()
+                .flat_map(|v| index_into(v, *index))
+                .collect(),
+            Selector::Wildcard => current.into_iter().flat_map(children).collect(),
+            Selector::RecursiveDescent => current.into_iter().flat_map(descendants).collect(),
+        };
+    }
+
+    current
+}
+
+fn child<'a>(value: &'a JsonValue, name: &str) -> Vec<&'a JsonValue> {
+    match value {
+        JsonValue::Container(Container::Object(members)) => members
+            .iter()
+            .filter(|member| member.name() == name)
+            .map(|member| member.value())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn index_into(value: &JsonValue, index: usize) -> Vec<&JsonValue> {
+    match value {
+        JsonValue::Container(Container::Array(elements)) => {
+            elements.get(index).into_iter().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn children(value: &JsonValue) -> Vec<&JsonValue> {
+    match value {
+        JsonValue::Container(Container::Object(members)) => {
+            members.iter().map(|member| member.value()).collect()
+        }
+        JsonValue::Container(Container::Array(elements)) => elements.iter().collect(),
+        JsonValue::Primitive(_) => Vec::new(),
+    }
+}
+
+fn descendants(value: &JsonValue) -> Vec<&JsonValue> {
+    let mut result = vec![value];
+
+    match value {
+        JsonValue::Container(Container::Object(members)) => {
+            for member in members {
+                result.extend(descendants(member.value()));
+            }
+        }
+        JsonValue::Container(Container::Array(elements)) => {
+            for element in elements {
+                result.extend(descendants(element));
+            }
+        }
+        JsonValue::Primitive(_) => {}
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_path, select, PathError, Selector};
+    use crate::ast::{Container, JsonValue, Member, Primitive};
+
+    fn sample() -> JsonValue {
+        JsonValue::Container(Container::Object(vec![Member::new(
+            "store".to_string(),
+            JsonValue::Container(Container::Object(vec![Member::new(
+                "books".to_string(),
+                JsonValue::Container(Container::Array(vec![
+                    JsonValue::Container(Container::Object(vec![Member::new(
+                        "title".to_string(),
+                        JsonValue::Primitive(Primitive::String("Dune".to_string())),
+                    )])),
+                    JsonValue::Container(Container::Object(vec![Member::new(
+                        "title".to_string(),
+                        JsonValue::Primitive(Primitive::String("Hyperion".to_string())),
+                    )])),
+                ])),
+            )])),
+        )]))
+    }
+
+    #[test]
+    fn test_parse_child_path() {
+        let selectors = parse_path("$.store.books").unwrap();
+        assert_eq!(
+            selectors,
+            vec![
+                Selector::Child("store".to_string()),
+                Selector::Child("books".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_index_and_bracket_child() {
+        let selectors = parse_path("$['store'].books[0]").unwrap();
+        assert_eq!(
+            selectors,
+            vec![
+                Selector::Child("store".to_string()),
+                Selector::Child("books".to_string()),
+                Selector::Index(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_wildcard_and_recursive_descent() {
+        assert_eq!(
+            parse_path("$.store.*").unwrap(),
+            vec![Selector::Child("store".to_string()), Selector::Wildcard]
+        );
+        assert_eq!(
+            parse_path("$..title").unwrap(),
+            vec![
+                Selector::RecursiveDescent,
+                Selector::Child("title".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unterminated_bracket_errors() {
+        assert_eq!(
+            parse_path("$['store"),
+            Err(PathError::UnterminatedBracket)
+        );
+        assert_eq!(parse_path("$.store[0"), Err(PathError::UnterminatedBracket));
+    }
+
+    #[test]
+    fn test_parse_empty_bracket_errors() {
+        assert_eq!(parse_path("$.store[]"), Err(PathError::InvalidIndex));
+    }
+
+    #[test]
+    fn test_parse_garbage_index_errors() {
+        assert_eq!(parse_path("$.store[abc]"), Err(PathError::InvalidIndex));
+    }
+
+    #[test]
+    fn test_parse_garbage_after_root_errors() {
+        assert_eq!(parse_path("$#name"), Err(PathError::UnexpectedCharacter));
+    }
+
+    #[test]
+    fn test_select_child_and_index() {
+        let value = sample();
+        let selectors = parse_path("$.store.books[1].title").unwrap();
+        let result = select(&value, &selectors);
+
+        assert_eq!(
+            result,
+            vec![&JsonValue::Primitive(Primitive::String("Hyperion".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_select_wildcard() {
+        let value = sample();
+        let selectors = parse_path("$.store.books[*].title").unwrap();
+        let result = select(&value, &selectors);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0],
+            &JsonValue::Primitive(Primitive::String("Dune".to_string()))
+        );
+        assert_eq!(
+            result[1],
+            &JsonValue::Primitive(Primitive::String("Hyperion".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_select_recursive_descent() {
+        let value = sample();
+        let selectors = parse_path("$..title").unwrap();
+        let result = select(&value, &selectors);
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_select_missing_child_returns_empty() {
+        let value = sample();
+        let selectors = parse_path("$.store.missing").unwrap();
+        let result = select(&value, &selectors);
+
+        assert!(result.is_empty());
+    }
+}
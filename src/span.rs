@@ -0,0 +1,14 @@
+/// A byte range `[start, end)` within the source text, paired with the
+/// line it starts on, used to point diagnostics at the offending text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize, line: usize) -> Self {
+        Self { start, end, line }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::ast::Primitive;
+use crate::error::{ParseError, ParseErrorKind, Result};
+use crate::token::{Token, TokenKind, TokenStream};
+
+/// One step of a streamed JSON document, yielded by `EventReader` without
+/// ever materializing the full `JsonValue` tree.
+#[derive(Debug, PartialEq)]
+pub enum Event {
+    StartObject,
+    EndObject,
+    StartArray,
+    EndArray,
+    Key(String),
+    Value(Primitive),
+}
+
+#[derive(Debug)]
+enum Frame {
+    Object { seen_entry: bool, awaiting_value: bool },
+    Array { seen_entry: bool },
+}
+
+/// Pulls one `Event` at a time from a `TokenStream`, tracking container
+/// nesting on an explicit stack instead of recursing, so large documents
+/// can be walked without ever holding the whole `JsonValue` tree in
+/// memory.
+pub struct EventReader<'a> {
+    ts: TokenStream<'a>,
+    stack: Vec<Frame>,
+    started: bool,
+    finished: bool,
+}
+
+impl<'a> EventReader<'a> {
+    pub fn new(ts: TokenStream<'a>) -> Self {
+        Self {
+            ts,
+            stack: Vec::new(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Pulls the next event, or `Ok(None)` once the top-level value has
+    /// been fully read and no tokens remain. Errors with
+    /// `ParseErrorKind::TrailingTokens` if another token follows the
+    /// top-level value.
+    pub fn next_event(&mut self) -> Result<Option<Event>> {
+        match self.stack.last() {
+            None => {
+                if !self.started {
+                    self.started = true;
+                    let token = self.ts.next()?;
+                    return self.start_value(token).map(Some);
+                }
+
+                if self.finished {
+                    return Ok(None);
+                }
+
+                self.finished = true;
+                match self.ts.next() {
+                    Ok(token) => Err(ParseError::new(token.span(), ParseErrorKind::TrailingTokens)),
+                    Err(e) if *e.kind() == ParseErrorKind::EndOfStream => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(Frame::Array { .. }) => self.advance_array(),
+            Some(Frame::Object { .. }) => self.advance_object(),
+        }
+    }
+
+    fn advance_array(&mut self) -> Result<Option<Event>> {
+        let seen_entry = match self.stack.last() {
+            Some(Frame::Array { seen_entry }) => *seen_entry,
+            _ => unreachable!(),
+        };
+
+        if seen_entry {
+            let token = self.ts.next()?;
+            match token.kind {
+                TokenKind::RightSquareBracket => {
+                    self.stack.pop();
+                    return Ok(Some(Event::EndArray));
+                }
+                TokenKind::Comma => {
+                    if self.ts.peek()?.kind == TokenKind::RightSquareBracket {
+                        return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    }
+                }
+                _ => return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken)),
+            }
+        } else if self.ts.peek()?.kind == TokenKind::RightSquareBracket {
+            self.ts.next()?;
+            self.stack.pop();
+            return Ok(Some(Event::EndArray));
+        }
+
+        let token = self.ts.next()?;
+        if let Some(Frame::Array { seen_entry }) = self.stack.last_mut() {
+            *seen_entry = true;
+        }
+        self.start_value(token).map(Some)
+    }
+
+    fn advance_object(&mut self) -> Result<Option<Event>> {
+        let (seen_entry, awaiting_value) = match self.stack.last() {
+            Some(Frame::Object {
+                seen_entry,
+                awaiting_value,
+            }) => (*seen_entry, *awaiting_value),
+            _ => unreachable!(),
+        };
+
+        if awaiting_value {
+            if let Some(Frame::Object { awaiting_value, .. }) = self.stack.last_mut() {
+                *awaiting_value = false;
+            }
+            let token = self.ts.next()?;
+            return self.start_value(token).map(Some);
+        }
+
+        if seen_entry {
+            let token = self.ts.next()?;
+            match token.kind {
+                TokenKind::RightCurlyBracket => {
+                    self.stack.pop();
+                    return Ok(Some(Event::EndObject));
+                }
+                TokenKind::Comma => {
+                    if self.ts.peek()?.kind == TokenKind::RightCurlyBracket {
+                        return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken));
+                    }
+                }
+                _ => return Err(ParseError::new(token.span(), ParseErrorKind::InvalidToken)),
+            }
+        } else if self.ts.peek()?.kind == TokenKind::RightCurlyBracket {
+            self.ts.next()?;
+            self.stack.pop();
+            return Ok(Some(Event::EndObject));
+        }
+
+        let key_token = self.ts.next()?;
+        let name = match key_token.kind {
+            TokenKind::String(name) => name,
+            _ => return Err(ParseError::new(key_token.span(), ParseErrorKind::InvalidToken)),
+        };
+
+        let colon = self.ts.next()?;
+        if colon.kind != TokenKind::Colon {
+            return Err(ParseError::new(colon.span(), ParseErrorKind::InvalidToken));
+        }
+
+        if let Some(Frame::Object {
+            seen_entry,
+            awaiting_value,
+        }) = self.stack.last_mut()
+        {
+            *seen_entry = true;
+            *awaiting_value = true;
+        }
+
+        Ok(Some(Event::Key(name)))
+    }
+
+    fn start_value(&mut self, token: Token) -> Result<Event> {
+        match token.kind {
+            TokenKind::LeftCurlyBracket => {
+                self.stack.push(Frame::Object {
+                    seen_entry: false,
+                    awaiting_value: false,
+                });
+                Ok(Event::StartObject)
+            }
+            TokenKind::LeftSquareBracket => {
+                self.stack.push(Frame::Array { seen_entry: false });
+                Ok(Event::StartArray)
+            }
+            TokenKind::String(value) => Ok(Event::Value(Primitive::String(value))),
+            TokenKind::Number(value) => Ok(Event::Value(Primitive::Number(value))),
+            TokenKind::True => Ok(Event::Value(Primitive::Boolean(true))),
+            TokenKind::False => Ok(Event::Value(Primitive::Boolean(false))),
+            TokenKind::Null => Ok(Event::Value(Primitive::Null)),
+            _ => Err(ParseError::new(token.span(), ParseErrorKind::NotAPrimitive)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, EventReader};
+    use crate::ast::Primitive;
+    use crate::token::TokenStream;
+
+    fn events(source: &str) -> Vec<Event> {
+        let mut reader = EventReader::new(TokenStream::new(source));
+        let mut events = Vec::new();
+
+        while let Some(event) = reader.next_event().unwrap() {
+            events.push(event);
+        }
+
+        events
+    }
+
+    #[test]
+    fn test_stream_primitive() {
+        assert_eq!(events("35"), vec![Event::Value(Primitive::Number(35.0))]);
+    }
+
+    #[test]
+    fn test_stream_empty_array() {
+        assert_eq!(events("[]"), vec![Event::StartArray, Event::EndArray]);
+    }
+
+    #[test]
+    fn test_stream_array() {
+        assert_eq!(
+            events("[1, 2, 3]"),
+            vec![
+                Event::StartArray,
+                Event::Value(Primitive::Number(1.0)),
+                Event::Value(Primitive::Number(2.0)),
+                Event::Value(Primitive::Number(3.0)),
+                Event::EndArray,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_empty_object() {
+        assert_eq!(events("{}"), vec![Event::StartObject, Event::EndObject]);
+    }
+
+    #[test]
+    fn test_stream_object() {
+        assert_eq!(
+            events("{\"name\": \"Alice\", \"age\": 30}"),
+            vec![
+                Event::StartObject,
+                Event::Key("name".to_string()),
+                Event::Value(Primitive::String("Alice".to_string())),
+                Event::Key("age".to_string()),
+                Event::Value(Primitive::Number(30.0)),
+                Event::EndObject,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_nested_containers() {
+        assert_eq!(
+            events("{\"values\": [1, [2, 3]]}"),
+            vec![
+                Event::StartObject,
+                Event::Key("values".to_string()),
+                Event::StartArray,
+                Event::Value(Primitive::Number(1.0)),
+                Event::StartArray,
+                Event::Value(Primitive::Number(2.0)),
+                Event::Value(Primitive::Number(3.0)),
+                Event::EndArray,
+                Event::EndArray,
+                Event::EndObject,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_trailing_comma_in_array_errors() {
+        let mut reader = EventReader::new(TokenStream::new("[1, 2,]"));
+
+        let mut result = reader.next_event();
+        while let Ok(Some(_)) = result {
+            result = reader.next_event();
+        }
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_stream_ends_after_top_level_value() {
+        let mut reader = EventReader::new(TokenStream::new("35"));
+
+        assert_eq!(
+            reader.next_event().unwrap(),
+            Some(Event::Value(Primitive::Number(35.0)))
+        );
+        assert_eq!(reader.next_event().unwrap(), None);
+        assert_eq!(reader.next_event().unwrap(), None);
+    }
+
+    #[test]
+    fn test_stream_trailing_tokens_error() {
+        let mut reader = EventReader::new(TokenStream::new("35 42"));
+
+        assert_eq!(
+            reader.next_event().unwrap(),
+            Some(Event::Value(Primitive::Number(35.0)))
+        );
+        assert!(reader.next_event().is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::{ParseError, ParseErrorKind, Result};
-use std::str::from_utf8;
+use crate::span::Span;
 
 #[derive(Debug)]
 pub struct TokenStream<'a> {
@@ -10,8 +10,8 @@ pub struct TokenStream<'a> {
 
 #[derive(Debug, PartialEq)]
 pub struct Token {
-    kind: TokenKind,
-    line: usize,
+    pub(crate) kind: TokenKind,
+    pub(crate) span: Span,
 }
 
 #[derive(Debug, PartialEq)]
@@ -32,8 +32,12 @@ pub enum TokenKind {
 }
 
 impl Token {
-    pub fn new(kind: TokenKind, line: usize) -> Self {
-        Self { kind, line }
+    pub fn new(kind: TokenKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
     }
 }
 
@@ -47,11 +51,26 @@ impl<'a> TokenStream<'a> {
     }
 
     pub fn next(&mut self) -> Result<Token> {
-        if self.end_of_stream() {
-            return Err(ParseError::new(self.line, ParseErrorKind::EndOfStream));
-        };
+        loop {
+            if self.end_of_stream() {
+                let span = Span::new(self.pointer, self.pointer, self.line);
+                return Err(ParseError::new(span, ParseErrorKind::EndOfStream));
+            }
+
+            match self.char_at_pointer() {
+                ' ' | '\r' | '\t' => self.pointer += 1,
+                '\n' => {
+                    self.pointer += 1;
+                    self.line += 1;
+                }
+                _ => break,
+            }
+        }
+
+        let start = self.pointer;
+        let line = self.line;
 
-        match self.char_at_pointer() {
+        let kind = match self.char_at_pointer() {
             '{' => self.single_token(TokenKind::LeftCurlyBracket),
             '}' => self.single_token(TokenKind::RightCurlyBracket),
             '[' => self.single_token(TokenKind::LeftSquareBracket),
@@ -59,19 +78,16 @@ impl<'a> TokenStream<'a> {
             ',' => self.single_token(TokenKind::Comma),
             ':' => self.single_token(TokenKind::Colon),
             '"' => self.tokenize_string(),
-            ' ' => {
-                self.pointer += 1;
-                self.next()
-            }
+            '-' => self.tokenize_number(),
             char if char.is_ascii_digit() => self.tokenize_number(),
             char if char.is_ascii_alphabetic() => self.tokenize_literal(),
-            '\n' | '\r' | '\t' => {
-                self.pointer += 1;
-                self.line += 1;
-                self.next()
+            _ => {
+                let span = Span::new(start, start + 1, line);
+                return Err(ParseError::new(span, ParseErrorKind::InvalidToken));
             }
-            _ => return Err(ParseError::new(self.line, ParseErrorKind::InvalidToken)),
-        }
+        }?;
+
+        Ok(Token::new(kind, Span::new(start, self.pointer, line)))
     }
 
     pub fn peek(&mut self) -> Result<Token> {
@@ -85,86 +101,219 @@ impl<'a> TokenStream<'a> {
         result
     }
 
-    fn tokenize_literal(&mut self) -> Result<Token> {
+    fn tokenize_literal(&mut self) -> Result<TokenKind> {
         let start = self.pointer;
 
         while !self.end_of_stream() && self.char_at_pointer().is_ascii_alphabetic() {
             self.pointer += 1;
         }
 
-        let bool_lexeme = from_utf8(&self.source.as_bytes()[start..self.pointer])
-            .map_err(|_| ParseError::new(self.line, ParseErrorKind::InvalidString))?;
+        let bool_lexeme = &self.source[start..self.pointer];
 
         match bool_lexeme {
-            "true" => Ok(Token::new(TokenKind::True, self.line)),
-            "false" => Ok(Token::new(TokenKind::False, self.line)),
-            "null" => Ok(Token::new(TokenKind::Null, self.line)),
-            _ => return Err(ParseError::new(self.line, ParseErrorKind::InvalidValue)),
+            "true" => Ok(TokenKind::True),
+            "false" => Ok(TokenKind::False),
+            "null" => Ok(TokenKind::Null),
+            _ => Err(self.error_at(start, ParseErrorKind::InvalidValue)),
         }
     }
 
-    fn tokenize_number(&mut self) -> Result<Token> {
+    fn tokenize_number(&mut self) -> Result<TokenKind> {
         let start = self.pointer;
 
-        //TODO: handle float numbers
-        while !self.end_of_stream() && self.char_at_pointer().is_ascii_digit() {
+        if self.char_at_pointer() == '-' {
+            self.pointer += 1;
+        }
+
+        if self.end_of_stream() || !self.char_at_pointer().is_ascii_digit() {
+            return Err(self.error_at(start, ParseErrorKind::InvalidNumber));
+        }
+
+        if self.char_at_pointer() == '0' {
             self.pointer += 1;
+        } else {
+            self.consume_digits();
         }
 
-        let number_lexeme = from_utf8(&self.source.as_bytes()[start..self.pointer])
-            .map_err(|_| ParseError::new(self.line, ParseErrorKind::InvalidString))?
-            .to_string();
-
-        Ok(Token::new(
-            TokenKind::Number(
-                number_lexeme
-                    .parse()
-                    .map_err(|_| ParseError::new(self.line, ParseErrorKind::InvalidNumber))?,
-            ),
-            self.line,
+        if !self.end_of_stream() && self.char_at_pointer() == '.' {
+            self.pointer += 1;
+
+            if self.end_of_stream() || !self.char_at_pointer().is_ascii_digit() {
+                return Err(self.error_at(start, ParseErrorKind::InvalidNumber));
+            }
+
+            self.consume_digits();
+        }
+
+        if !self.end_of_stream() && matches!(self.char_at_pointer(), 'e' | 'E') {
+            self.pointer += 1;
+
+            if !self.end_of_stream() && matches!(self.char_at_pointer(), '+' | '-') {
+                self.pointer += 1;
+            }
+
+            if self.end_of_stream() || !self.char_at_pointer().is_ascii_digit() {
+                return Err(self.error_at(start, ParseErrorKind::InvalidNumber));
+            }
+
+            self.consume_digits();
+        }
+
+        let number_lexeme = &self.source[start..self.pointer];
+
+        Ok(TokenKind::Number(
+            number_lexeme
+                .parse()
+                .map_err(|_| self.error_at(start, ParseErrorKind::InvalidNumber))?,
         ))
     }
 
-    fn tokenize_string(&mut self) -> Result<Token> {
+    fn consume_digits(&mut self) {
+        while !self.end_of_stream() && self.char_at_pointer().is_ascii_digit() {
+            self.pointer += 1;
+        }
+    }
+
+    fn tokenize_string(&mut self) -> Result<TokenKind> {
         let start = self.pointer;
 
         self.pointer += 1;
 
-        while !self.end_of_stream() && self.char_at_pointer() != '"' {
-            if self.char_at_pointer() == '\n' {
-                return Err(ParseError::new(
-                    self.line,
-                    ParseErrorKind::UnterminatedString,
-                ));
+        let mut value = String::new();
+
+        loop {
+            if self.end_of_stream() {
+                return Err(self.error_at(start, ParseErrorKind::UnterminatedString));
+            }
+
+            match self.char_at_pointer() {
+                '"' => {
+                    self.pointer += 1;
+                    break;
+                }
+                '\n' => {
+                    return Err(self.error_at(start, ParseErrorKind::UnterminatedString));
+                }
+                '\\' => {
+                    self.pointer += 1;
+                    value.push(self.tokenize_escape(start)?);
+                }
+                _ => {
+                    let ch = self.source[self.pointer..]
+                        .chars()
+                        .next()
+                        .expect("not at end of stream");
+                    value.push(ch);
+                    self.pointer += ch.len_utf8();
+                }
             }
-            self.pointer += 1;
         }
 
+        Ok(TokenKind::String(value))
+    }
+
+    fn tokenize_escape(&mut self, string_start: usize) -> Result<char> {
         if self.end_of_stream() {
-            return Err(ParseError::new(
-                self.line,
-                ParseErrorKind::UnterminatedString,
-            ));
+            return Err(self.error_at(string_start, ParseErrorKind::UnterminatedString));
         }
 
-        let string = from_utf8(&self.source.as_bytes()[start..=self.pointer])
-            .map_err(|_| ParseError::new(self.line, ParseErrorKind::InvalidString))?
-            .to_string();
+        if self.char_at_pointer() == 'u' {
+            self.pointer += 1;
+            return self.tokenize_unicode_escape(string_start);
+        }
 
-        let result = Token::new(TokenKind::String(string.clone()), self.line);
+        let escaped = match self.char_at_pointer() {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            _ => return Err(self.error_at(string_start, ParseErrorKind::InvalidString)),
+        };
 
         self.pointer += 1;
+        Ok(escaped)
+    }
+
+    fn tokenize_unicode_escape(&mut self, string_start: usize) -> Result<char> {
+        let high = self.read_hex4(string_start)?;
+
+        if (0xD800..=0xDBFF).contains(&high) {
+            if self.end_of_stream() || self.char_at_pointer() != '\\' {
+                return Err(self.error_at(string_start, ParseErrorKind::InvalidString));
+            }
+            self.pointer += 1;
+
+            if self.end_of_stream() || self.char_at_pointer() != 'u' {
+                return Err(self.error_at(string_start, ParseErrorKind::InvalidString));
+            }
+            self.pointer += 1;
+
+            let low = self.read_hex4(string_start)?;
 
-        Ok(result)
+            if !(0xDC00..=0xDFFF).contains(&low) {
+                return Err(self.error_at(string_start, ParseErrorKind::InvalidString));
+            }
+
+            let codepoint = 0x10000 + (high - 0xD800) * 0x400 + (low - 0xDC00);
+            return char::from_u32(codepoint)
+                .ok_or_else(|| self.error_at(string_start, ParseErrorKind::InvalidString));
+        }
+
+        char::from_u32(high).ok_or_else(|| self.error_at(string_start, ParseErrorKind::InvalidString))
     }
-    fn single_token(&mut self, kind: TokenKind) -> Result<Token> {
-        let result = Token::new(kind, self.line);
+
+    // The naive `&self.source[start..end]` slice panics if `end` doesn't
+    // land on a char boundary, which a malformed `\uXXXX` escape can
+    // trigger; `from_utf8` on the raw bytes turns that into a catchable
+    // `InvalidString` error instead.
+    #[allow(clippy::string_from_utf8_as_bytes)]
+    fn read_hex4(&mut self, string_start: usize) -> Result<u32> {
+        if self.pointer + 4 > self.source.len() {
+            return Err(self.error_at(string_start, ParseErrorKind::InvalidString));
+        }
+
+        let hex = std::str::from_utf8(&self.source.as_bytes()[self.pointer..self.pointer + 4])
+            .map_err(|_| self.error_at(string_start, ParseErrorKind::InvalidString))?;
+
+        let value = u32::from_str_radix(hex, 16)
+            .map_err(|_| self.error_at(string_start, ParseErrorKind::InvalidString))?;
+
+        self.pointer += 4;
+        Ok(value)
+    }
+
+    fn single_token(&mut self, kind: TokenKind) -> Result<TokenKind> {
         self.pointer += 1;
-        Ok(result)
+        Ok(kind)
+    }
+
+    fn error_at(&self, start: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(Span::new(start, self.pointer, self.line), kind)
+    }
+
+    pub(crate) fn end_of_stream(&self) -> bool {
+        self.source.len() <= self.pointer
     }
 
-    fn end_of_stream(&self) -> bool {
-        !(self.source.len() > self.pointer)
+    /// Advances the raw source position until the next structural
+    /// boundary (`,`, `}`, `]`, or end of stream) without tokenizing
+    /// what is skipped. Used by error-recovery parsing to resynchronize
+    /// after an unexpected token or a byte sequence that cannot be lexed.
+    pub(crate) fn skip_to_boundary(&mut self) {
+        while !self.end_of_stream() {
+            match self.char_at_pointer() {
+                ',' | '}' | ']' => return,
+                '\n' => {
+                    self.pointer += 1;
+                    self.line += 1;
+                }
+                _ => self.pointer += 1,
+            }
+        }
     }
 
     fn char_at_pointer(&self) -> char {
@@ -177,33 +326,99 @@ mod token_test {
     use std::fs;
 
     use super::TokenStream;
-    use crate::token::{Token, TokenKind};
+    use crate::token::TokenKind;
 
     #[test]
     fn test_single_token() {
         let mut ts = TokenStream::new(",:{}[]");
-        assert_eq!(ts.next().unwrap(), Token::new(TokenKind::Comma, 1))
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Comma)
     }
 
     #[test]
     fn test_string() {
         let mut ts = TokenStream::new("\"Cleitonrasta\"");
         assert_eq!(
-            ts.next().unwrap(),
-            Token::new(TokenKind::String(String::from("\"Cleitonrasta\"")), 1,)
+            ts.next().unwrap().kind,
+            TokenKind::String(String::from("Cleitonrasta"))
+        )
+    }
+
+    #[test]
+    fn test_string_raw_multibyte_utf8() {
+        let mut ts = TokenStream::new("\"héllo 日本語\"");
+        assert_eq!(
+            ts.next().unwrap().kind,
+            TokenKind::String(String::from("héllo 日本語"))
+        )
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let mut ts = TokenStream::new("\"a\\nb\\tc\\\"d\\\\e\"");
+        assert_eq!(
+            ts.next().unwrap().kind,
+            TokenKind::String(String::from("a\nb\tc\"d\\e"))
+        )
+    }
+
+    #[test]
+    fn test_string_unicode_escape() {
+        let mut ts = TokenStream::new("\"\\u0041\"");
+        assert_eq!(
+            ts.next().unwrap().kind,
+            TokenKind::String(String::from("A"))
+        )
+    }
+
+    #[test]
+    fn test_string_surrogate_pair_escape() {
+        let mut ts = TokenStream::new("\"\\uD83D\\uDE00\"");
+        assert_eq!(
+            ts.next().unwrap().kind,
+            TokenKind::String(String::from("\u{1F600}"))
         )
     }
 
+    #[test]
+    fn test_string_unicode_escape_splits_multibyte_char_errors() {
+        let mut ts = TokenStream::new("\"\\uaéé\"");
+        assert!(ts.next().is_err())
+    }
+
     #[test]
     fn test_number() {
         let mut ts = TokenStream::new("64");
-        assert_eq!(ts.next().unwrap(), Token::new(TokenKind::Number(64.0), 1))
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Number(64.0))
+    }
+
+    #[test]
+    fn test_negative_number() {
+        let mut ts = TokenStream::new("-5");
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Number(-5.0))
+    }
+
+    #[test]
+    fn test_float_number() {
+        let mut ts = TokenStream::new("12.34");
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Number(12.34))
+    }
+
+    #[test]
+    fn test_exponent_number() {
+        let mut ts = TokenStream::new("1e10");
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Number(1e10))
+    }
+
+    #[test]
+    fn test_negative_exponent_number() {
+        let mut ts = TokenStream::new("2.5e-3");
+        assert_eq!(ts.next().unwrap().kind, TokenKind::Number(2.5e-3))
     }
 
     #[test]
     fn test_literals() {
         let mut ts = TokenStream::new("true false null");
-        assert_eq!(ts.next().unwrap(), Token::new(TokenKind::True, 1))
+        assert_eq!(ts.next().unwrap().kind, TokenKind::True)
     }
 
     #[test]
@@ -213,42 +428,42 @@ mod token_test {
 
         let expected_tokens = vec![
             TokenKind::LeftCurlyBracket,
-            TokenKind::String("\"name\"".to_string()),
+            TokenKind::String("name".to_string()),
             TokenKind::Colon,
-            TokenKind::String("\"Alice\"".to_string()),
+            TokenKind::String("Alice".to_string()),
             TokenKind::Comma,
-            TokenKind::String("\"age\"".to_string()),
+            TokenKind::String("age".to_string()),
             TokenKind::Colon,
             TokenKind::Number(30.0),
             TokenKind::Comma,
-            TokenKind::String("\"isStudent\"".to_string()),
+            TokenKind::String("isStudent".to_string()),
             TokenKind::Colon,
             TokenKind::False,
             TokenKind::Comma,
-            TokenKind::String("\"skills\"".to_string()),
+            TokenKind::String("skills".to_string()),
             TokenKind::Colon,
             TokenKind::LeftSquareBracket,
-            TokenKind::String("\"Rust\"".to_string()),
+            TokenKind::String("Rust".to_string()),
             TokenKind::Comma,
-            TokenKind::String("\"JavaScript\"".to_string()),
+            TokenKind::String("JavaScript".to_string()),
             TokenKind::Comma,
-            TokenKind::String("\"Python\"".to_string()),
+            TokenKind::String("Python".to_string()),
             TokenKind::RightSquareBracket,
             TokenKind::Comma,
-            TokenKind::String("\"address\"".to_string()),
+            TokenKind::String("address".to_string()),
             TokenKind::Colon,
             TokenKind::LeftCurlyBracket,
-            TokenKind::String("\"street\"".to_string()),
+            TokenKind::String("street".to_string()),
             TokenKind::Colon,
-            TokenKind::String("\"123 Maple Street\"".to_string()),
+            TokenKind::String("123 Maple Street".to_string()),
             TokenKind::Comma,
-            TokenKind::String("\"city\"".to_string()),
+            TokenKind::String("city".to_string()),
             TokenKind::Colon,
-            TokenKind::String("\"Wonderland\"".to_string()),
+            TokenKind::String("Wonderland".to_string()),
             TokenKind::Comma,
-            TokenKind::String("\"zip\"".to_string()),
+            TokenKind::String("zip".to_string()),
             TokenKind::Colon,
-            TokenKind::String("\"12345\"".to_string()),
+            TokenKind::String("12345".to_string()),
             TokenKind::RightCurlyBracket,
             TokenKind::RightCurlyBracket,
         ];
@@ -280,4 +495,25 @@ mod token_test {
 
         assert_eq!(peeked, next);
     }
+
+    #[test]
+    fn test_token_span() {
+        let mut ts = TokenStream::new("  \"hi\"");
+        let token = ts.next().unwrap();
+
+        assert_eq!(token.span().start, 2);
+        assert_eq!(token.span().end, 6);
+        assert_eq!(token.span().line, 1);
+    }
+
+    #[test]
+    fn test_token_span_tracks_lines() {
+        let mut ts = TokenStream::new("1\n2");
+        ts.next().unwrap();
+        let token = ts.next().unwrap();
+
+        assert_eq!(token.span().line, 2);
+        assert_eq!(token.span().start, 2);
+        assert_eq!(token.span().end, 3);
+    }
 }